@@ -1,16 +1,21 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, ModifierKeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, ModifierKeyCode,
+        MouseButton, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use serde::{Deserialize, Serialize};
 use std::{
     error::Error,
-    io,
+    fs, io,
+    path::PathBuf,
     time::{Duration, Instant},
 };
 use tui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
     widgets::{Block, BorderType, Borders, List, ListItem, ListState},
@@ -33,6 +38,7 @@ impl BoolToggleExt for bool {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 struct TodoItem {
     is_done: bool,
     title: String,
@@ -42,7 +48,7 @@ impl ToString for TodoItem {
     fn to_string(&self) -> String {
         String::from(format!(
             "[{}] {}",
-            if self.is_done { " " } else { "x" },
+            if self.is_done { "x" } else { " " },
             self.title
         ))
     }
@@ -53,6 +59,21 @@ struct TodoList {
     items: Vec<TodoItem>,
 }
 
+fn data_file_path() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+            PathBuf::from(home).join(".local/share")
+        });
+    base.join("rust_todo").join("todos.json")
+}
+
+enum InputMode {
+    Normal,
+    Editing(String),
+}
+
 impl TodoList {
     fn new(items: Vec<TodoItem>) -> TodoList {
         TodoList {
@@ -104,6 +125,10 @@ impl TodoList {
     }
 
     fn next(&mut self) {
+        if self.items.is_empty() {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i >= self.items.len() - 1 {
@@ -118,6 +143,10 @@ impl TodoList {
     }
 
     fn previous(&mut self) {
+        if self.items.is_empty() {
+            self.state.select(None);
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
@@ -134,6 +163,282 @@ impl TodoList {
     fn unselec(&mut self) {
         self.state.select(None);
     }
+
+    fn first(&mut self) {
+        if self.items.is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(0));
+        }
+    }
+
+    fn last(&mut self) {
+        if self.items.is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(self.items.len() - 1));
+        }
+    }
+
+    fn toggle_done(&mut self) {
+        if let Some(item) = self.state.selected().and_then(|i| self.items.get_mut(i)) {
+            item.is_done.toggle();
+        }
+    }
+
+    fn remove_selected(&mut self) {
+        let Some(i) = self.state.selected() else {
+            return;
+        };
+        self.items.remove(i);
+        if self.items.is_empty() {
+            self.state.select(None);
+        } else if i >= self.items.len() {
+            self.state.select(Some(self.items.len() - 1));
+        }
+    }
+
+}
+
+struct Project {
+    name: String,
+    todos: TodoList,
+}
+
+impl Project {
+    fn new(name: impl Into<String>, todos: TodoList) -> Project {
+        Project {
+            name: name.into(),
+            todos,
+        }
+    }
+}
+
+/// Plain serializable view of `Project` — only what should survive a
+/// restart.
+#[derive(Serialize, Deserialize)]
+struct ProjectData {
+    name: String,
+    items: Vec<TodoItem>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AppData {
+    projects: Vec<ProjectData>,
+}
+
+enum Focus {
+    Projects,
+    Todos,
+}
+
+struct App {
+    projects: Vec<Project>,
+    project_state: ListState,
+    focus: Focus,
+    /// `Rect`s the todo list and projects list were last rendered into, so
+    /// mouse events (given in terminal row/column) can be translated into
+    /// an item index and used to tell which pane was clicked.
+    todos_area: Rect,
+    projects_area: Rect,
+    /// Scroll offsets, tracked by hand because `tui::widgets::ListState`'s
+    /// offset has no public accessor — kept in sync with `List`'s own
+    /// scrolling in `ui` via `scroll_todos_into_view` and
+    /// `scroll_projects_into_view`.
+    todos_scroll: usize,
+    projects_scroll: usize,
+}
+
+impl App {
+    fn default() -> App {
+        App::with_projects(vec![Project::new("Inbox", TodoList::default())])
+    }
+
+    fn with_projects(projects: Vec<Project>) -> App {
+        let mut project_state = ListState::default();
+        if !projects.is_empty() {
+            project_state.select(Some(0));
+        }
+        App {
+            projects,
+            project_state,
+            focus: Focus::Todos,
+            todos_area: Rect::default(),
+            projects_area: Rect::default(),
+            todos_scroll: 0,
+            projects_scroll: 0,
+        }
+    }
+
+    /// Loads the workspace from `data_file_path()`, falling back to
+    /// `default()` when the file doesn't exist yet or its contents parse as
+    /// neither format below; any other read error is returned. `todos.json`
+    /// files in the older single-list format (a bare `items` array) are
+    /// migrated into a single "Inbox" project rather than treated as a hard
+    /// error.
+    fn load() -> io::Result<App> {
+        let path = data_file_path();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(App::default()),
+            Err(err) => return Err(err),
+        };
+
+        if let Ok(data) = serde_json::from_str::<AppData>(&contents) {
+            let projects = data
+                .projects
+                .into_iter()
+                .map(|p| Project::new(p.name, TodoList::new(p.items)))
+                .collect();
+            return Ok(App::with_projects(projects));
+        }
+
+        #[derive(Deserialize)]
+        struct LegacyTodoListData {
+            items: Vec<TodoItem>,
+        }
+        if let Ok(legacy) = serde_json::from_str::<LegacyTodoListData>(&contents) {
+            return Ok(App::with_projects(vec![Project::new(
+                "Inbox",
+                TodoList::new(legacy.items),
+            )]));
+        }
+
+        Ok(App::default())
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let path = data_file_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let data = AppData {
+            projects: self
+                .projects
+                .iter()
+                .map(|p| ProjectData {
+                    name: p.name.clone(),
+                    items: p.todos.items.clone(),
+                })
+                .collect(),
+        };
+        let contents = serde_json::to_string_pretty(&data)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, contents)
+    }
+
+    fn selected_project_mut(&mut self) -> Option<&mut Project> {
+        self.project_state
+            .selected()
+            .and_then(|i| self.projects.get_mut(i))
+    }
+
+    fn next_project(&mut self) {
+        if self.projects.is_empty() {
+            self.project_state.select(None);
+            return;
+        }
+        let i = match self.project_state.selected() {
+            Some(i) if i >= self.projects.len() - 1 => 0,
+            Some(i) => i + 1,
+            None => 0,
+        };
+        self.project_state.select(Some(i));
+    }
+
+    fn previous_project(&mut self) {
+        if self.projects.is_empty() {
+            self.project_state.select(None);
+            return;
+        }
+        let i = match self.project_state.selected() {
+            Some(0) | None => self.projects.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.project_state.select(Some(i));
+    }
+
+    fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            Focus::Projects => Focus::Todos,
+            Focus::Todos => Focus::Projects,
+        };
+    }
+
+    /// Which pane, if any, a mouse position landed in.
+    fn pane_at(&self, column: u16, row: u16) -> Option<Focus> {
+        if rect_contains(self.todos_area, column, row) {
+            Some(Focus::Todos)
+        } else if rect_contains(self.projects_area, column, row) {
+            Some(Focus::Projects)
+        } else {
+            None
+        }
+    }
+
+    /// Translates a terminal row/column (as reported by a mouse event) into
+    /// an index into the selected project's todos. `None` if the click
+    /// landed outside the list or there's no project selected.
+    fn todo_index_at(&self, column: u16, row: u16) -> Option<usize> {
+        let project = self.projects.get(self.project_state.selected()?)?;
+        row_index_at(self.todos_area, self.todos_scroll, project.todos.items.len(), column, row)
+    }
+
+    /// Translates a terminal row/column into an index into `projects`.
+    /// `None` if the click landed outside the list.
+    fn project_index_at(&self, column: u16, row: u16) -> Option<usize> {
+        row_index_at(self.projects_area, self.projects_scroll, self.projects.len(), column, row)
+    }
+
+    /// Keeps `todos_scroll` tracking the selected row the same way
+    /// `List`'s own (unobservable) scroll offset would, so mouse math in
+    /// `todo_index_at` lines up with what's actually drawn.
+    fn scroll_todos_into_view(&mut self, viewport_height: usize) {
+        let selected = self.selected_project_mut().and_then(|p| p.todos.state.selected());
+        self.todos_scroll = clamp_scroll(self.todos_scroll, selected, viewport_height);
+    }
+
+    /// Same as `scroll_todos_into_view`, but for the projects pane.
+    fn scroll_projects_into_view(&mut self, viewport_height: usize) {
+        let selected = self.project_state.selected();
+        self.projects_scroll = clamp_scroll(self.projects_scroll, selected, viewport_height);
+    }
+}
+
+/// `true` if `(column, row)` falls inside `area`.
+fn rect_contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.x + area.width && row >= area.y && row < area.y + area.height
+}
+
+/// Translates a terminal row/column into an item index for a bordered list
+/// rendered at `area`, given its current scroll offset and item count.
+/// `None` if the position falls outside the list's inner (border-excluded)
+/// rect, or past the last item.
+fn row_index_at(area: Rect, scroll: usize, len: usize, column: u16, row: u16) -> Option<usize> {
+    let inner = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    if column < inner.x || column >= inner.x + inner.width || row < inner.y || row >= inner.y + inner.height {
+        return None;
+    }
+    let idx = (row - inner.y) as usize + scroll;
+    (idx < len).then_some(idx)
+}
+
+/// Mirrors the scroll-into-view clamping `tui::widgets::List` performs
+/// internally (but doesn't expose), so a hand-tracked offset stays in sync
+/// with what's actually rendered.
+fn clamp_scroll(scroll: usize, selected: Option<usize>, viewport_height: usize) -> usize {
+    match selected {
+        None => 0,
+        Some(i) if viewport_height == 0 => i,
+        Some(i) if i < scroll => i,
+        Some(i) if i >= scroll + viewport_height => i + 1 - viewport_height,
+        Some(_) => scroll,
+    }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -145,6 +450,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    install_panic_hook();
+
     // create app and run it
     let res = run_app(&mut terminal);
 
@@ -164,32 +471,169 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Restores the terminal to a sane state before handing off to the default
+/// panic hook, so a panic mid-draw doesn't leave the user's shell stuck in
+/// raw mode / the alternate screen.
+fn install_panic_hook() {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        original_hook(panic_info);
+    }));
+}
+
 fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
     let mut ui_config = UiConfig {
         projects: true,
         typebox: true,
     };
 
-    let mut todo_list = TodoList::default();
+    let mut app = App::load()?;
+    let mut input_mode = InputMode::Normal;
+
+    let tick_rate = Duration::from_millis(250);
+    let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| ui(f, ui_config, &mut todo_list))?;
-
-        if let Event::Key(key) = event::read()? {
-            match key.code {
-                KeyCode::Esc => return Ok(()),
-                KeyCode::End => ui_config.projects.toggle(),
-                KeyCode::Home => ui_config.typebox.toggle(),
-                KeyCode::Down => todo_list.next(),
-                KeyCode::Up => todo_list.previous(),
-                KeyCode::Left => todo_list.unselec(),
+        terminal.draw(|f| ui(f, ui_config, &mut app, &input_mode))?;
+
+        let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+        let has_event = event::poll(timeout)?;
+
+        if has_event {
+            match event::read()? {
+                Event::Mouse(mouse) if matches!(input_mode, InputMode::Normal) => {
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            match app.pane_at(mouse.column, mouse.row) {
+                                Some(Focus::Todos) => {
+                                    app.focus = Focus::Todos;
+                                    if let Some(idx) = app.todo_index_at(mouse.column, mouse.row) {
+                                        if let Some(project) = app.selected_project_mut() {
+                                            let already_selected =
+                                                project.todos.state.selected() == Some(idx);
+                                            project.todos.state.select(Some(idx));
+                                            if already_selected {
+                                                project.todos.toggle_done();
+                                                app.save()?;
+                                            }
+                                        }
+                                    }
+                                }
+                                Some(Focus::Projects) => {
+                                    app.focus = Focus::Projects;
+                                    if let Some(idx) = app.project_index_at(mouse.column, mouse.row)
+                                    {
+                                        app.project_state.select(Some(idx));
+                                    }
+                                }
+                                None => (),
+                            }
+                        }
+                        MouseEventKind::ScrollDown => match app.pane_at(mouse.column, mouse.row) {
+                            Some(Focus::Todos) => {
+                                if let Some(project) = app.selected_project_mut() {
+                                    project.todos.next();
+                                }
+                            }
+                            Some(Focus::Projects) => app.next_project(),
+                            None => (),
+                        },
+                        MouseEventKind::ScrollUp => match app.pane_at(mouse.column, mouse.row) {
+                            Some(Focus::Todos) => {
+                                if let Some(project) = app.selected_project_mut() {
+                                    project.todos.previous();
+                                }
+                            }
+                            Some(Focus::Projects) => app.previous_project(),
+                            None => (),
+                        },
+                        _ => (),
+                    }
+                }
+                Event::Key(key) => match &mut input_mode {
+                    InputMode::Normal => match key.code {
+                        KeyCode::Esc => {
+                            app.save()?;
+                            return Ok(());
+                        }
+                        KeyCode::Char('p') => ui_config.projects.toggle(),
+                        KeyCode::Char('t') => ui_config.typebox.toggle(),
+                        KeyCode::Tab | KeyCode::BackTab => app.toggle_focus(),
+                        KeyCode::Down | KeyCode::Up | KeyCode::Left | KeyCode::Home
+                        | KeyCode::End | KeyCode::Char(' ') | KeyCode::Delete => {
+                            match app.focus {
+                                Focus::Projects => match key.code {
+                                    KeyCode::Down => app.next_project(),
+                                    KeyCode::Up => app.previous_project(),
+                                    _ => (),
+                                },
+                                Focus::Todos => {
+                                    if let Some(project) = app.selected_project_mut() {
+                                        match key.code {
+                                            KeyCode::Down => project.todos.next(),
+                                            KeyCode::Up => project.todos.previous(),
+                                            KeyCode::Left => project.todos.unselec(),
+                                            KeyCode::Home => project.todos.first(),
+                                            KeyCode::End => project.todos.last(),
+                                            KeyCode::Char(' ') => {
+                                                project.todos.toggle_done();
+                                                app.save()?;
+                                            }
+                                            KeyCode::Delete => {
+                                                project.todos.remove_selected();
+                                                app.save()?;
+                                            }
+                                            _ => (),
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        KeyCode::Char('i') | KeyCode::Char('a') => {
+                            input_mode = InputMode::Editing(String::new());
+                        }
+                        _ => (),
+                    },
+                    InputMode::Editing(buffer) => match key.code {
+                        KeyCode::Char(c) => buffer.push(c),
+                        KeyCode::Backspace => {
+                            buffer.pop();
+                        }
+                        KeyCode::Enter => {
+                            let title = std::mem::take(buffer).trim().to_string();
+                            input_mode = InputMode::Normal;
+                            if !title.is_empty() {
+                                if let Some(project) = app.selected_project_mut() {
+                                    project.todos.add(TodoItem {
+                                        is_done: false,
+                                        title,
+                                    });
+                                }
+                                app.save()?;
+                            }
+                        }
+                        KeyCode::Esc => input_mode = InputMode::Normal,
+                        _ => (),
+                    },
+                },
                 _ => (),
             }
         }
+
+        if last_tick.elapsed() >= tick_rate {
+            on_tick();
+            last_tick = Instant::now();
+        }
     }
 }
 
-fn ui<B: Backend>(f: &mut Frame<B>, config: UiConfig, data: &mut TodoList) {
+/// Runs once per `tick_rate`, independent of key events. A no-op today —
+/// the hook future periodic work (autosave, cursor blink) can build on.
+fn on_tick() {}
+
+fn ui<B: Backend>(f: &mut Frame<B>, config: UiConfig, app: &mut App, input_mode: &InputMode) {
     // Wrapping block for a group
     // Just draw the block and the group on the same area and build the group
     // with at least a margin of 1
@@ -226,46 +670,138 @@ fn ui<B: Backend>(f: &mut Frame<B>, config: UiConfig, data: &mut TodoList) {
         )
         .split(chunks[0]);
 
-    let items: Vec<ListItem> = data
-        .items
+    app.todos_area = top_chunks[0];
+    app.projects_area = top_chunks[1];
+    app.scroll_todos_into_view(top_chunks[0].height.saturating_sub(2) as usize);
+    app.scroll_projects_into_view(top_chunks[1].height.saturating_sub(2) as usize);
+
+    let selected = app.project_state.selected().and_then(|i| app.projects.get_mut(i));
+
+    match selected {
+        Some(project) => {
+            let items: Vec<ListItem> = project
+                .todos
+                .items
+                .iter()
+                .map(|i| {
+                    ListItem::new(i.to_string().clone())
+                        .style(Style::default().fg(Color::Black).bg(Color::White))
+                })
+                .collect();
+
+            let list = List::new(items)
+                .block(Block::default().borders(Borders::ALL).title(project.name.clone()))
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::LightGreen)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol(">> ");
+
+            f.render_stateful_widget(list, top_chunks[0], &mut project.todos.state);
+        }
+        None => {
+            let block = Block::default().borders(Borders::ALL).title("List");
+            f.render_widget(block, top_chunks[0]);
+        }
+    }
+
+    // Top right inner block with styled title, centered
+    let project_items: Vec<ListItem> = app
+        .projects
         .iter()
-        .map(|i| {
-            ListItem::new(i.to_string().clone())
-                .style(Style::default().fg(Color::Black).bg(Color::White))
-        })
+        .map(|p| ListItem::new(p.name.clone()))
         .collect();
 
-    let items = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("List"))
-        .highlight_style(
-            Style::default()
-                .bg(Color::LightGreen)
-                .add_modifier(Modifier::BOLD),
+    let projects_title = Span::styled(
+        "Projects",
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Black)
+            .add_modifier(Modifier::BOLD),
+    );
+    let projects_highlight = if matches!(app.focus, Focus::Projects) {
+        Style::default()
+            .bg(Color::LightGreen)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().add_modifier(Modifier::BOLD)
+    };
+    let projects_list = List::new(project_items)
+        .block(
+            Block::default()
+                .title(projects_title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL),
         )
+        .highlight_style(projects_highlight)
         .highlight_symbol(">> ");
+    f.render_stateful_widget(projects_list, top_chunks[1], &mut app.project_state);
 
-    f.render_stateful_widget(items, top_chunks[0], &mut data.state);
+    let block = Block::default().title("Text").borders(Borders::ALL);
+    let inner = block.inner(chunks[1]);
+    f.render_widget(block, chunks[1]);
+
+    if let InputMode::Editing(buffer) = input_mode {
+        let text = tui::widgets::Paragraph::new(buffer.as_str());
+        f.render_widget(text, inner);
+        let caret = (buffer.chars().count() as u16).min(inner.width.saturating_sub(1));
+        f.set_cursor(inner.x + caret, inner.y);
+    }
+}
 
-    // // Top left inner block with green background
-    // let block = Block::default()
-    //     .style(Style::default().bg(Color::Reset))
-    //     .borders(Borders::ALL)
-    //     .border_type(BorderType::Thick)
-    //     .border_style(Style::default().fg(Color::Cyan));
-    // f.render_widget(block, top_chunks[0]);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Top right inner block with styled title aligned to the right
-    let block = Block::default()
-        .title(Span::styled(
-            "Projects",
-            Style::default()
-                .fg(Color::White)
-                .bg(Color::Black)
-                .add_modifier(Modifier::BOLD),
-        ))
-        .title_alignment(Alignment::Center);
-    f.render_widget(block, top_chunks[1]);
+    fn bordered(x: u16, y: u16, width: u16, height: u16) -> Rect {
+        Rect { x, y, width, height }
+    }
 
-    let block = Block::default().title("Text").borders(Borders::ALL);
-    f.render_widget(block, chunks[1])
+    #[test]
+    fn row_index_at_accounts_for_border_and_scroll() {
+        let area = bordered(0, 0, 10, 5); // inner rows 1..=3
+        assert_eq!(row_index_at(area, 0, 5, 2, 1), Some(0));
+        assert_eq!(row_index_at(area, 0, 5, 2, 3), Some(2));
+        assert_eq!(row_index_at(area, 2, 5, 2, 1), Some(2));
+    }
+
+    #[test]
+    fn row_index_at_rejects_border_and_out_of_bounds_positions() {
+        let area = bordered(0, 0, 10, 5);
+        assert_eq!(row_index_at(area, 0, 5, 0, 0), None); // top border
+        assert_eq!(row_index_at(area, 0, 5, 0, 4), None); // bottom border
+        assert_eq!(row_index_at(area, 0, 2, 2, 3), None); // past last item
+    }
+
+    #[test]
+    fn clamp_scroll_follows_selection_into_view() {
+        assert_eq!(clamp_scroll(0, None, 3), 0);
+        assert_eq!(clamp_scroll(0, Some(0), 3), 0);
+        assert_eq!(clamp_scroll(0, Some(2), 3), 0);
+        assert_eq!(clamp_scroll(0, Some(3), 3), 1); // scrolls down to keep row 3 visible
+        assert_eq!(clamp_scroll(5, Some(1), 3), 1); // scrolls up when selection moves above
+    }
+
+    #[test]
+    fn remove_selected_clamps_to_new_last_index() {
+        let mut list = TodoList::new(vec![
+            TodoItem { is_done: false, title: "a".into() },
+            TodoItem { is_done: false, title: "b".into() },
+            TodoItem { is_done: false, title: "c".into() },
+        ]);
+        list.state.select(Some(2));
+        list.remove_selected();
+        assert_eq!(list.items.len(), 2);
+        assert_eq!(list.state.selected(), Some(1));
+    }
+
+    #[test]
+    fn remove_selected_unselects_when_list_becomes_empty() {
+        let mut list = TodoList::new(vec![TodoItem { is_done: false, title: "only".into() }]);
+        list.state.select(Some(0));
+        list.remove_selected();
+        assert!(list.items.is_empty());
+        assert_eq!(list.state.selected(), None);
+    }
 }